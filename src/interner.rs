@@ -9,17 +9,17 @@ use {
         ops::Deref,
         ptr::NonNull,
     },
-    lock_api::{RawRwLock, RwLock},
+    lock_api::{RawRwLock, RawRwLockUpgrade, RwLock, RwLockUpgradableReadGuard},
 };
 
+use core::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
 #[cfg(not(feature = "std"))]
-use alloc::boxed::Box;
+use alloc::{boxed::Box, sync::Arc};
 #[cfg(feature = "std")]
-use std::collections::hash_map::RandomState;
+use std::{collections::hash_map::RandomState, sync::Arc};
 
-#[cfg(feature = "raw")]
-use hashbrown::hash_map::RawEntryMut;
-use hashbrown::hash_map::{Entry, HashMap};
+use hashbrown::hash_map::{Entry, HashMap, RawEntryMut};
 
 #[cfg(feature = "std")]
 use crate::std_lock_api::StdRawRwLock;
@@ -28,7 +28,7 @@ use crate::std_lock_api::StdRawRwLock;
 /// uses raw-pointer borrowing rules to avoid invalidating extant references.
 ///
 /// The resolved reference is guaranteed valid until the PinBox is dropped.
-struct PinBox<T: ?Sized> {
+pub(crate) struct PinBox<T: ?Sized> {
     ptr: NonNull<T>,
     _marker: PhantomData<Box<T>>,
 }
@@ -50,9 +50,7 @@ impl<T: ?Sized> PinBox<T> {
 impl<T: ?Sized> Drop for PinBox<T> {
     fn drop(&mut self) {
         #[allow(unsafe_code)] // SAFETY: PinBox acts like Box.
-        unsafe {
-            Box::from_raw(self.ptr.as_ptr())
-        };
+        let _ = unsafe { Box::from_raw(self.ptr.as_ptr()) };
     }
 }
 
@@ -116,18 +114,24 @@ unsafe impl<T: ?Sized> Send for PinBox<T> where Box<T>: Send {}
 #[allow(unsafe_code)] // SAFETY: PinBox acts like Box.
 unsafe impl<T: ?Sized> Sync for PinBox<T> where Box<T>: Sync {}
 
+/// The backing storage shape shared by every interner variant: a locked
+/// `HashMap` keyed on pinned, boxed values. `V` is `()` for a plain value
+/// set and `Box<AtomicUsize>` for [`RcInterner`]'s per-entry reference
+/// count.
+type Arena<T, S, R, V = ()> = RwLock<R, HashMap<PinBox<T>, V, S>>;
+
 #[cfg(feature = "std")]
 /// An interner based on a `HashSet`. See the crate-level docs for more.
 #[derive(Debug)]
 pub struct Interner<T: ?Sized, S = RandomState, R: RawRwLock = StdRawRwLock> {
-    arena: RwLock<R, HashMap<PinBox<T>, (), S>>,
+    pub(crate) arena: Arena<T, S, R>,
 }
 
 #[cfg(not(feature = "std"))]
 /// An interner based on a `HashSet`. See the crate-level docs for more.
 #[derive(Debug)]
-pub struct Interner<T: ?Sized, S, R: RawRwLock> {
-    arena: RwLock<R, HashMap<PinBox<T>, (), S>>,
+pub struct Interner<T: ?Sized, S, R: RawRwLock = crate::spin_lock::SpinRawRwLock> {
+    pub(crate) arena: Arena<T, S, R>,
 }
 
 impl<T: ?Sized, S: Default, R: RawRwLock + Default> Default for Interner<T, S, R> {
@@ -199,6 +203,105 @@ impl<T: Eq + Hash + ?Sized, S: BuildHasher, R: RawRwLock> Interner<T, S, R> {
     }
 }
 
+impl<T: Eq + Hash + ?Sized, S: BuildHasher + Clone, R: RawRwLockUpgrade> Interner<T, S, R> {
+    /// Intern an item into the interner via a single upgradeable-read
+    /// critical section.
+    ///
+    /// [`Interner::intern`] checks for a cache hit under a shared lock, then
+    /// drops it and re-checks under a freshly acquired exclusive lock,
+    /// which can waste a `Box` allocation if another thread interns the
+    /// same item in between. This path instead takes one upgradeable-read
+    /// guard for the whole operation: the lookup runs while holding it (an
+    /// upgradeable guard still allows other readers in, but blocks other
+    /// writers and upgraders), and it is only promoted to an exclusive lock
+    /// if the key turns out to be absent. The key's hash is computed once
+    /// and reused for both the lookup and the insert, so a miss hashes and
+    /// boxes the value at most once, and no other thread can race in an
+    /// insert of the same key between the lookup and the upgrade.
+    ///
+    /// Requires a lock backend that also implements
+    /// [`RawRwLockUpgrade`](lock_api::RawRwLockUpgrade), such as
+    /// [`SpinRawRwLock`](crate::SpinRawRwLock). Backends that only
+    /// implement [`RawRwLock`] must use [`Interner::intern`] instead.
+    pub fn intern_upgradable<B>(&self, t: B) -> Interned<'_, T>
+    where
+        B: Borrow<T> + Into<Box<T>>,
+    {
+        let borrowed = t.borrow();
+
+        let arena = self.arena.upgradable_read();
+        let hash = arena.hasher().hash_one(borrowed);
+
+        #[allow(unsafe_code)] // SAFETY: Interned ties the lifetime to the interner.
+        if let Some((k, _)) = arena.raw_entry().from_hash(hash, |k| (**k).eq(borrowed)) {
+            return Interned(unsafe { k.as_ref() });
+        }
+
+        // Clone the hasher before upgrading: insert_with_hasher needs it to
+        // rehash the existing entries if the map grows, and the map itself
+        // is about to become mutably borrowed by the entry below.
+        let hash_builder = arena.hasher().clone();
+        let mut arena = RwLockUpgradableReadGuard::upgrade(arena);
+
+        #[allow(unsafe_code)] // SAFETY: Interned ties the lifetime to the interner.
+        match arena.raw_entry_mut().from_hash(hash, |k| (**k).eq(borrowed)) {
+            RawEntryMut::Occupied(entry) => Interned(unsafe { entry.key().as_ref() }),
+            RawEntryMut::Vacant(entry) => {
+                let boxed = PinBox::new(t.into());
+                let interned = Interned(unsafe { boxed.as_ref() });
+                entry.insert_with_hasher(hash, boxed, (), |k| hash_builder.hash_one(&**k));
+                interned
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod intern_upgradable_tests {
+    use super::Interner;
+    use crate::spin_lock::SpinRawRwLock;
+
+    // intern_upgradable requires a RawRwLockUpgrade backend; StdRawRwLock
+    // doesn't implement it, so these tests use SpinRawRwLock instead.
+    fn interner() -> Interner<str, std::collections::hash_map::RandomState, SpinRawRwLock> {
+        Interner::default()
+    }
+
+    #[test]
+    fn interning_the_same_value_twice_dedups() {
+        let interner = interner();
+
+        let a = interner.intern_upgradable("value");
+        let b = interner.intern_upgradable("value");
+
+        assert_eq!(&*a, "value");
+        assert!(core::ptr::eq(&*a, &*b));
+    }
+
+    #[test]
+    fn agrees_with_intern_on_distinctness_and_dedup() {
+        let interner = interner();
+
+        let values = ["a", "b", "c", "a", "b", "d"];
+        let via_upgradable: Vec<_> = values
+            .iter()
+            .map(|v| interner.intern_upgradable(*v))
+            .collect();
+        let via_intern: Vec<_> = values.iter().map(|v| interner.intern(*v)).collect();
+
+        for i in 0..values.len() {
+            for j in 0..values.len() {
+                assert_eq!(
+                    core::ptr::eq(&*via_upgradable[i], &*via_upgradable[j]),
+                    core::ptr::eq(&*via_intern[i], &*via_intern[j]),
+                    "identity of values[{i}] vs values[{j}] disagrees between \
+                     intern_upgradable and intern"
+                );
+            }
+        }
+    }
+}
+
 #[allow(unsafe_code)]
 #[cfg(feature = "raw")]
 impl<T: ?Sized, S, R: RawRwLock> Interner<T, S, R> {
@@ -291,3 +394,586 @@ impl<T: ?Sized, H: BuildHasher, R: RawRwLock> Interner<T, H, R> {
         }
     }
 }
+
+/// An interner that shards its backing map across several independently
+/// locked partitions.
+///
+/// `Interner` serializes every cache miss behind one exclusive lock over the
+/// whole backing map. `ShardedInterner` instead picks one of several
+/// `RwLock<R, HashMap<..>>` shards per operation, so unrelated `intern` calls
+/// that land in different shards no longer contend with each other. The
+/// shard is chosen from the high bits of the key's hash after multiplying it
+/// by a fixed odd constant, which decorrelates the shard index from both the
+/// low bits the shard's own `HashMap` buckets on and the top bits it uses as
+/// probe tags, so shard choice and in-map lookup stay independent.
+///
+/// `Interned<'_, T>` semantics are unchanged: each value is still boxed with
+/// [`PinBox`] and pinned for the interner's lifetime, so only the lock
+/// granularity changes, not the identity guarantees. See the crate-level
+/// docs for more.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ShardedInterner<T: ?Sized, S = RandomState, R: RawRwLock = StdRawRwLock> {
+    shards: Box<[Arena<T, S, R>]>,
+    hash_builder: S,
+    // log2(shards.len()); shards.len() is always a power of two so that the
+    // shard index can be taken from the hash's high bits with a plain shift.
+    shard_bits: u32,
+}
+
+/// An interner that shards its backing map across several independently
+/// locked partitions. See the crate-level docs for more.
+#[cfg(not(feature = "std"))]
+#[derive(Debug)]
+pub struct ShardedInterner<T: ?Sized, S, R: RawRwLock> {
+    shards: Box<[Arena<T, S, R>]>,
+    hash_builder: S,
+    shard_bits: u32,
+}
+
+impl<T: ?Sized, S, R: RawRwLock> ShardedInterner<T, S, R> {
+    /// Smallest allowed shard count (as a power of two exponent): 1 shard.
+    const MIN_SHARD_BITS: u32 = 0;
+    /// Largest allowed shard count (as a power of two exponent): 1024 shards.
+    const MAX_SHARD_BITS: u32 = 10;
+
+    #[allow(unsafe_code)] // SAFETY: Interned ties the lifetime to the interner.
+    fn shard_for(&self, hash: u64) -> &Arena<T, S, R> {
+        // shard_bits == 0 means there is exactly one shard and a shift of 64
+        // would be out of range, so special-case it away.
+        let index = if self.shard_bits == 0 {
+            0
+        } else {
+            // hashbrown derives its SIMD-probing control-byte tag (h2) from
+            // the hash's top 7 bits, and its bucket index (h1) from the low
+            // bits. Taking the shard index straight from the hash's top
+            // bits would alias h2: for shard_bits >= 7 every entry routed
+            // to the same shard would then also share the same h2 tag,
+            // collapsing that shard's tag filter into linear probing.
+            // Multiplying by a fixed odd (Fibonacci hashing) constant first
+            // spreads all 64 input bits across the output, so the shard
+            // index is decorrelated from both h1 and h2.
+            const FIBONACCI_HASH_MULTIPLIER: u64 = 0x9E37_79B9_7F4A_7C15;
+            hash.wrapping_mul(FIBONACCI_HASH_MULTIPLIER) >> (64 - self.shard_bits)
+        };
+        &self.shards[index as usize]
+    }
+}
+
+impl<T: ?Sized, S: Clone, R: RawRwLock> ShardedInterner<T, S, R> {
+    fn with_shard_count_and_hasher(shards: usize, hasher: S) -> Self {
+        let shard_bits = shards
+            .max(1)
+            .next_power_of_two()
+            .trailing_zeros()
+            .clamp(Self::MIN_SHARD_BITS, Self::MAX_SHARD_BITS);
+
+        let shards = (0..1usize << shard_bits)
+            .map(|_| RwLock::new(HashMap::with_hasher(hasher.clone())))
+            .collect();
+
+        ShardedInterner {
+            shards,
+            hash_builder: hasher,
+            shard_bits,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> ShardedInterner<T> {
+    /// Create an empty sharded interner, with a shard count derived from the
+    /// available parallelism.
+    ///
+    /// The default is `(num_cpus * 4).next_power_of_two()`, clamped to
+    /// between 1 and 1024 shards.
+    pub fn new() -> Self {
+        Self::with_shards(Self::default_shard_count())
+    }
+
+    fn default_shard_count() -> usize {
+        let cpus = std::thread::available_parallelism().map_or(1, core::num::NonZeroUsize::get);
+        (cpus * 4).next_power_of_two()
+    }
+
+    /// Create an empty sharded interner with (approximately) the given
+    /// number of shards.
+    ///
+    /// `shards` is rounded up to the next power of two and clamped to
+    /// between 1 and 1024.
+    pub fn with_shards(shards: usize) -> Self {
+        Self::with_shard_count_and_hasher(shards, RandomState::default())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> Default for ShardedInterner<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Constructors to control the backing `HashMap`'s hash function.
+impl<T: ?Sized, H: BuildHasher + Clone, R: RawRwLock> ShardedInterner<T, H, R> {
+    /// Create an empty sharded interner with (approximately) the given
+    /// number of shards, using `hasher` to hash the values and to pick a
+    /// shard.
+    ///
+    /// `shards` is rounded up to the next power of two and clamped to
+    /// between 1 and 1024.
+    pub fn with_shards_and_hasher(shards: usize, hasher: H) -> Self {
+        Self::with_shard_count_and_hasher(shards, hasher)
+    }
+}
+
+impl<T: Eq + Hash + ?Sized, S: BuildHasher, R: RawRwLock> ShardedInterner<T, S, R> {
+    /// Intern an item into the interner.
+    ///
+    /// Behaves exactly like [`Interner::intern`], except that the lock taken
+    /// to do so only ever contends with operations that hash into the same
+    /// shard.
+    pub fn intern<B>(&self, t: B) -> Interned<'_, T>
+    where
+        B: Borrow<T> + Into<Box<T>>,
+    {
+        let borrowed = t.borrow();
+        let hash = self.hash_builder.hash_one(borrowed);
+
+        if let Some(interned) = self.get_with_hash(borrowed, hash) {
+            return interned;
+        }
+
+        let mut shard = self.shard_for(hash).write();
+
+        #[allow(unsafe_code)] // SAFETY: Interned ties the lifetime to the interner.
+        match shard.raw_entry_mut().from_hash(hash, |k| (**k).eq(borrowed)) {
+            RawEntryMut::Occupied(entry) => Interned(unsafe { entry.key().as_ref() }),
+            RawEntryMut::Vacant(entry) => {
+                let boxed = PinBox::new(t.into());
+                let interned = Interned(unsafe { boxed.as_ref() });
+                let hash_builder = &self.hash_builder;
+                entry.insert_with_hasher(hash, boxed, (), |k| hash_builder.hash_one(&**k));
+                interned
+            },
+        }
+    }
+
+    /// Get an interned reference out of this interner.
+    ///
+    /// Behaves exactly like [`Interner::get`].
+    pub fn get(&self, t: &T) -> Option<Interned<'_, T>> {
+        let hash = self.hash_builder.hash_one(t);
+        self.get_with_hash(t, hash)
+    }
+
+    fn get_with_hash(&self, t: &T, hash: u64) -> Option<Interned<'_, T>> {
+        #[allow(unsafe_code)] // SAFETY: Interned ties the lifetime to the interner.
+        self.shard_for(hash)
+            .read()
+            .raw_entry()
+            .from_hash(hash, |k| (**k).eq(t))
+            .map(|(k, _)| Interned(unsafe { k.as_ref() }))
+    }
+}
+
+#[cfg(test)]
+mod sharded_interner_tests {
+    use super::{Interner, ShardedInterner};
+
+    #[test]
+    fn interning_the_same_value_twice_dedups() {
+        let interner = ShardedInterner::<str>::with_shards(4);
+
+        let a = interner.intern("value");
+        let b = interner.intern("value");
+
+        assert_eq!(&*a, "value");
+        assert!(core::ptr::eq(&*a, &*b));
+    }
+
+    #[test]
+    fn get_only_finds_already_interned_values() {
+        let interner = ShardedInterner::<str>::with_shards(4);
+
+        assert!(interner.get("value").is_none());
+        let interned = interner.intern("value");
+
+        assert!(core::ptr::eq(&*interned, &*interner.get("value").unwrap()));
+    }
+
+    #[test]
+    fn agrees_with_plain_interner_on_distinctness_and_dedup() {
+        // Many shards maximises the odds of exercising shard_for's hash
+        // mixing, since ShardedInterner::with_shards rounds up to a power
+        // of two and clamps to MAX_SHARD_BITS.
+        let sharded = ShardedInterner::<str>::with_shards(1024);
+        let plain = Interner::<str>::new();
+
+        let values = ["a", "b", "c", "a", "b", "d"];
+        let sharded_interned: Vec<_> = values.iter().map(|v| sharded.intern(*v)).collect();
+        let plain_interned: Vec<_> = values.iter().map(|v| plain.intern(*v)).collect();
+
+        for i in 0..values.len() {
+            for j in 0..values.len() {
+                let sharded_same = core::ptr::eq(&*sharded_interned[i], &*sharded_interned[j]);
+                let plain_same = core::ptr::eq(&*plain_interned[i], &*plain_interned[j]);
+                assert_eq!(
+                    sharded_same, plain_same,
+                    "identity of values[{i}] vs values[{j}] disagrees between \
+                     ShardedInterner and Interner"
+                );
+            }
+        }
+    }
+}
+
+#[allow(unsafe_code)]
+#[cfg(feature = "raw")]
+impl<T: ?Sized, S, R: RawRwLock> ShardedInterner<T, S, R> {
+    /// Raw interning interface for any `T`.
+    ///
+    /// Behaves exactly like [`Interner::intern_raw`], except that the shard
+    /// is selected from the same `hash` that is used for the lookup.
+    pub fn intern_raw<Q>(
+        &self,
+        it: Q,
+        hash: u64,
+        mut is_match: impl FnMut(&Q, &T) -> bool,
+        do_hash: impl Fn(&T) -> u64,
+        commit: impl FnOnce(Q) -> Box<T>,
+    ) -> Interned<'_, T> {
+        if let Some(interned) = self.get_raw(hash, |t| is_match(&it, t)) {
+            return interned;
+        }
+
+        let mut shard = self.shard_for(hash).write();
+
+        match shard.raw_entry_mut().from_hash(hash, |t| is_match(&it, t)) {
+            RawEntryMut::Occupied(entry) => Interned(unsafe { entry.key().as_ref() }),
+            RawEntryMut::Vacant(entry) => {
+                let boxed = PinBox::new(commit(it));
+                let interned = Interned(unsafe { boxed.as_ref() });
+                entry.insert_with_hasher(hash, boxed, (), |t| do_hash(t));
+                interned
+            },
+        }
+    }
+
+    /// Raw interned reference lookup.
+    ///
+    /// Behaves exactly like [`Interner::get_raw`].
+    pub fn get_raw(&self, hash: u64, mut is_match: impl FnMut(&T) -> bool) -> Option<Interned<'_, T>> {
+        #[allow(unsafe_code)] // SAFETY: Interned ties the lifetime to the interner.
+        self.shard_for(hash)
+            .read()
+            .raw_entry()
+            .from_hash(hash, |t| is_match(t))
+            .map(|(t, _)| Interned(unsafe { t.as_ref() }))
+    }
+}
+
+#[cfg(feature = "std")]
+/// An owned, reference-counted handle into an [`RcInterner`].
+///
+/// Unlike [`Interned`], which borrows from the interner, `RcInterned` keeps
+/// both its entry *and the interner's backing map* alive by reference
+/// count: cloning it increments the entry's count and dropping it
+/// decrements the count, but the entry's allocation is only actually freed
+/// by a later call to [`RcInterner::sweep`]. Internally it holds its own
+/// `Arc` clone of the backing map, so the map -- and therefore the `ptr`
+/// and `count` this handle points into -- stays alive even after every
+/// [`RcInterner`] that could reach it has been dropped.
+pub struct RcInterned<T: ?Sized, S = RandomState, R: RawRwLock = StdRawRwLock> {
+    ptr: NonNull<T>,
+    count: NonNull<AtomicUsize>,
+    // Keeps `ptr` and `count` valid even once every `RcInterner` handle to
+    // this map has been dropped.
+    arena: Arc<Arena<T, S, R, Box<AtomicUsize>>>,
+}
+
+#[cfg(not(feature = "std"))]
+/// An owned, reference-counted handle into an [`RcInterner`]. See the
+/// crate-level docs for more.
+pub struct RcInterned<T: ?Sized, S, R: RawRwLock = crate::spin_lock::SpinRawRwLock> {
+    ptr: NonNull<T>,
+    count: NonNull<AtomicUsize>,
+    // Keeps `ptr` and `count` valid even once every `RcInterner` handle to
+    // this map has been dropped.
+    arena: Arc<Arena<T, S, R, Box<AtomicUsize>>>,
+}
+
+#[allow(unsafe_code)] // SAFETY: RcInterned acts like an Arc to the interned value.
+unsafe impl<T, S, R> Send for RcInterned<T, S, R>
+where
+    T: ?Sized + Send + Sync,
+    S: Send + Sync,
+    R: RawRwLock + Send + Sync,
+{
+}
+#[allow(unsafe_code)] // SAFETY: RcInterned acts like an Arc to the interned value.
+unsafe impl<T, S, R> Sync for RcInterned<T, S, R>
+where
+    T: ?Sized + Send + Sync,
+    S: Send + Sync,
+    R: RawRwLock + Send + Sync,
+{
+}
+
+impl<T: ?Sized, S, R: RawRwLock> Deref for RcInterned<T, S, R> {
+    type Target = T;
+
+    #[allow(unsafe_code)] // SAFETY: the entry outlives this handle, see RcInterner::sweep.
+    fn deref(&self) -> &T {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T: ?Sized, S, R: RawRwLock> Clone for RcInterned<T, S, R> {
+    fn clone(&self) -> Self {
+        #[allow(unsafe_code)] // SAFETY: the entry outlives this handle, see RcInterner::sweep.
+        let old_count = unsafe { self.count.as_ref() }.fetch_add(1, AtomicOrdering::Relaxed);
+        debug_assert!(old_count > 0, "RcInterned cloned after its count reached zero");
+
+        RcInterned {
+            ptr: self.ptr,
+            count: self.count,
+            arena: Arc::clone(&self.arena),
+        }
+    }
+}
+
+impl<T: ?Sized, S, R: RawRwLock> Drop for RcInterned<T, S, R> {
+    fn drop(&mut self) {
+        // The entry is not freed here: `sweep` takes the exclusive lock and
+        // frees it later, once it observes the count at zero.
+        #[allow(unsafe_code)] // SAFETY: the entry outlives this handle, see RcInterner::sweep.
+        unsafe { self.count.as_ref() }.fetch_sub(1, AtomicOrdering::Release);
+    }
+}
+
+impl<T: ?Sized + fmt::Debug, S, R: RawRwLock> fmt::Debug for RcInterned<T, S, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+/// A reference-counted variant of [`Interner`].
+///
+/// A plain `Interner` keeps every interned entry alive for its own lifetime,
+/// so a long-lived interner grows monotonically. `RcInterner` instead hands
+/// out an owned [`RcInterned`] handle that tracks how many copies of it are
+/// still alive, and [`sweep`](RcInterner::sweep) can reclaim the entries
+/// nobody holds a handle to anymore.
+///
+/// The backing map is held behind an `Arc`, and every `RcInterned` handle
+/// keeps its own clone of that `Arc`: this is what lets a handle safely
+/// outlive the `RcInterner` that created it. Each entry's reference count
+/// lives in its own `Box<AtomicUsize>` rather than inline in the map, so its
+/// address stays stable even if the backing `HashMap` reallocates or
+/// rehashes while an `RcInterned` handle to it is outstanding, exactly as
+/// [`PinBox`] keeps each value's address stable.
+///
+/// See the crate-level docs for more.
+#[derive(Debug)]
+pub struct RcInterner<T: ?Sized, S = RandomState, R: RawRwLock = StdRawRwLock> {
+    arena: Arc<Arena<T, S, R, Box<AtomicUsize>>>,
+}
+
+/// A reference-counted variant of [`Interner`]. See the crate-level docs for
+/// more.
+#[cfg(not(feature = "std"))]
+#[derive(Debug)]
+pub struct RcInterner<T: ?Sized, S, R: RawRwLock = crate::spin_lock::SpinRawRwLock> {
+    arena: Arc<Arena<T, S, R, Box<AtomicUsize>>>,
+}
+
+impl<T: ?Sized, S: Default, R: RawRwLock + Default> Default for RcInterner<T, S, R> {
+    fn default() -> Self {
+        RcInterner {
+            arena: Arc::new(RwLock::default()),
+        }
+    }
+}
+
+impl<T: Eq + Hash + ?Sized, S: BuildHasher, R: RawRwLock> RcInterner<T, S, R> {
+    /// Intern an item, returning an owned, reference-counted handle to it.
+    ///
+    /// If the item is already interned, this bumps its reference count
+    /// instead of re-inserting it; the count is always bumped (or the entry
+    /// re-created) while holding at least a shared lock on the backing map,
+    /// so a concurrent [`sweep`](RcInterner::sweep) -- which needs the
+    /// exclusive lock to free anything -- can never free an entry out from
+    /// under a caller that is about to receive a handle to it.
+    ///
+    /// See [`Interner::intern`] for the borrowing semantics this builds on.
+    pub fn intern<B>(&self, t: B) -> RcInterned<T, S, R>
+    where
+        B: Borrow<T> + Into<Box<T>>,
+    {
+        let borrowed = t.borrow();
+
+        {
+            let arena = self.arena.read();
+            if let Some((k, count)) = arena.get_key_value(borrowed) {
+                count.fetch_add(1, AtomicOrdering::Relaxed);
+                #[allow(unsafe_code)] // SAFETY: RcInterned keeps the entry alive via its count.
+                return RcInterned {
+                    ptr: NonNull::from(unsafe { k.as_ref() }),
+                    count: NonNull::from(&**count),
+                    arena: Arc::clone(&self.arena),
+                };
+            }
+        }
+
+        let mut arena = self.arena.write();
+
+        #[allow(unsafe_code)] // SAFETY: RcInterned keeps the entry alive via its count.
+        match arena.entry(PinBox::new(t.into())) {
+            Entry::Occupied(entry) => {
+                // Someone else interned the same item while we held no lock.
+                let count = entry.get();
+                count.fetch_add(1, AtomicOrdering::Relaxed);
+                RcInterned {
+                    ptr: NonNull::from(unsafe { entry.key().as_ref() }),
+                    count: NonNull::from(&**count),
+                    arena: Arc::clone(&self.arena),
+                }
+            },
+            Entry::Vacant(entry) => {
+                let ptr = NonNull::from(unsafe { entry.key().as_ref() });
+                let count = Box::new(AtomicUsize::new(1));
+                let count_ptr = NonNull::from(&*count);
+                entry.insert(count);
+                RcInterned {
+                    ptr,
+                    count: count_ptr,
+                    arena: Arc::clone(&self.arena),
+                }
+            },
+        }
+    }
+
+    /// Get a reference-counted handle to an already-interned item, if any.
+    ///
+    /// Unlike [`intern`](Self::intern), this never inserts the item.
+    pub fn get(&self, t: &T) -> Option<RcInterned<T, S, R>> {
+        let arena = self.arena.read();
+        let (k, count) = arena.get_key_value(t)?;
+        count.fetch_add(1, AtomicOrdering::Relaxed);
+        #[allow(unsafe_code)] // SAFETY: RcInterned keeps the entry alive via its count.
+        Some(RcInterned {
+            ptr: NonNull::from(unsafe { k.as_ref() }),
+            count: NonNull::from(&**count),
+            arena: Arc::clone(&self.arena),
+        })
+    }
+
+    /// Remove every interned entry with a reference count of zero, freeing
+    /// its allocation.
+    ///
+    /// # Safety invariant
+    /// An entry is only ever freed here, and only while the exclusive lock
+    /// is held and its count has just been observed to be exactly zero.
+    /// [`intern`](Self::intern) and [`get`](Self::get) always bump (or
+    /// re-create) an entry's count while holding at least a shared lock, so
+    /// a concurrent `sweep` can never free an entry a caller is about to be
+    /// handed.
+    pub fn sweep(&self) {
+        let mut arena = self.arena.write();
+        arena.retain(|_, count| count.load(AtomicOrdering::Acquire) != 0);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> RcInterner<T> {
+    /// Create an empty reference-counted interner.
+    pub fn new() -> Self {
+        RcInterner {
+            arena: Arc::new(RwLock::new(HashMap::default())),
+        }
+    }
+
+    /// Create an empty reference-counted interner with the specified
+    /// capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        RcInterner {
+            arena: Arc::new(RwLock::new(HashMap::with_capacity_and_hasher(
+                capacity,
+                RandomState::default(),
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> Default for RcInterner<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Constructors to control the backing `HashMap`'s hash function.
+impl<T: ?Sized, H: BuildHasher, R: RawRwLock> RcInterner<T, H, R> {
+    /// Create an empty reference-counted interner which will use the given
+    /// hasher to hash the values.
+    pub fn with_hasher(hasher: H) -> Self {
+        RcInterner {
+            arena: Arc::new(RwLock::new(HashMap::with_hasher(hasher))),
+        }
+    }
+
+    /// Create an empty reference-counted interner with the specified
+    /// capacity, using `hasher` to hash the values.
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: H) -> Self {
+        RcInterner {
+            arena: Arc::new(RwLock::new(HashMap::with_capacity_and_hasher(
+                capacity, hasher,
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod rc_interner_tests {
+    use super::RcInterner;
+
+    #[test]
+    fn sweep_reclaims_only_zero_count_entries() {
+        let interner = RcInterner::<str>::new();
+
+        let kept = interner.intern("kept");
+        interner.intern("dropped");
+
+        interner.sweep();
+
+        assert_eq!(interner.get("kept").as_deref(), Some("kept"));
+        assert_eq!(interner.get("dropped").as_deref(), None);
+        drop(kept);
+    }
+
+    #[test]
+    fn resurrect_after_sweep_reinserts_the_entry() {
+        let interner = RcInterner::<str>::new();
+
+        interner.intern("value");
+        interner.sweep();
+        assert_eq!(interner.get("value").as_deref(), None);
+
+        let resurrected = interner.intern("value");
+        assert_eq!(&*resurrected, "value");
+        assert_eq!(interner.get("value").as_deref(), Some("value"));
+    }
+
+    #[test]
+    fn handle_outlives_every_interner_that_reaches_it() {
+        let interner = RcInterner::<str>::new();
+        let handle = interner.intern("value");
+
+        // Dropping every `RcInterner` handle to the backing map must not
+        // invalidate `handle`: it keeps its own `Arc` clone of the map alive.
+        drop(interner);
+
+        assert_eq!(&*handle, "value");
+    }
+}
@@ -0,0 +1,95 @@
+//! A thread-safe interner, with pluggable locking and optional sharding,
+//! reference counting, and `serde` support. See the individual types for
+//! details:
+//!
+//! - [`Interner`]: the base interner, a locked `HashSet`-like arena that
+//!   hands out [`Interned`] references tied to its own lifetime.
+//! - [`ShardedInterner`]: shards [`Interner`]'s backing map across several
+//!   independently locked partitions to cut write-lock contention.
+//! - [`RcInterner`]: a reference-counted variant that hands out owned
+//!   [`RcInterned`] handles instead of borrowing from the interner.
+//!
+//! All of the above are generic over the lock backend `R: RawRwLock`, so
+//! that `no_std` users without access to [`std::sync::RwLock`] can plug in
+//! their own, such as the bundled [`SpinRawRwLock`].
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+use core::{borrow::Borrow, cmp::Ordering, fmt, hash::Hash, ops::Deref};
+
+mod interner;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod spin_lock;
+#[cfg(feature = "std")]
+mod std_lock_api;
+
+pub use crate::{
+    interner::{Interner, RcInterned, RcInterner, ShardedInterner},
+    spin_lock::{RelaxStrategy, Spin, SpinRawRwLock},
+};
+
+#[cfg(feature = "std")]
+pub use crate::{spin_lock::Yield, std_lock_api::StdRawRwLock};
+
+/// A borrowed handle to an interned value, tied to the lifetime of the
+/// [`Interner`] (or [`ShardedInterner`]) it was returned from.
+///
+/// Two `Interned` handles for equal values, produced by the same interner,
+/// always wrap references to the very same allocation: the wrapped
+/// reference's *address* can be used as a cheap proxy for equality of the
+/// interned value.
+pub struct Interned<'a, T: ?Sized>(pub(crate) &'a T);
+
+impl<T: ?Sized> Clone for Interned<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Interned<'_, T> {}
+
+impl<T: ?Sized> Deref for Interned<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        self.0
+    }
+}
+
+impl<T: ?Sized> Borrow<T> for Interned<'_, T> {
+    fn borrow(&self) -> &T {
+        self.0
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Interned<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: ?Sized + Eq> Eq for Interned<'_, T> {}
+impl<T: ?Sized + PartialEq> PartialEq for Interned<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        (**self).eq(&**other)
+    }
+}
+
+impl<T: ?Sized + Ord> Ord for Interned<'_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+impl<T: ?Sized + PartialOrd> PartialOrd for Interned<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: ?Sized + Hash> Hash for Interned<'_, T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
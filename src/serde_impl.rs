@@ -0,0 +1,94 @@
+//! Implements `serde::{Serialize, Deserialize}` for [`Interner`], gated
+//! behind the `serde` feature, so a populated interner (e.g. a symbol
+//! table) can be persisted and reloaded across runs.
+//!
+//! Because an [`Interned`](crate::Interned) reference can never outlive the
+//! interner it came from, deserializing always builds a fresh, empty
+//! interner and re-interns every element into it. This preserves value
+//! membership, but **not** identity or pointer stability: the same value
+//! deserialized twice (or interned again after a round trip) does not
+//! necessarily land at the same address, even though it will still compare
+//! equal and deduplicate correctly against further `intern` calls.
+
+use core::{
+    borrow::Borrow,
+    fmt,
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+
+use serde::{
+    de::{Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeSeq, Serializer},
+};
+
+use lock_api::RawRwLock;
+
+use crate::Interner;
+
+impl<T, S, R> Serialize for Interner<T, S, R>
+where
+    T: ?Sized + Eq + Hash + Serialize,
+    S: BuildHasher,
+    R: RawRwLock,
+{
+    /// Serialize the set of currently interned values as a sequence.
+    ///
+    /// Takes a shared lock on the interner for the duration of the call.
+    fn serialize<Se: Serializer>(&self, serializer: Se) -> Result<Se::Ok, Se::Error> {
+        let arena = self.arena.read();
+
+        let mut seq = serializer.serialize_seq(Some(arena.len()))?;
+        for value in arena.keys() {
+            // `value` is the `PinBox<T>` key; deref it down to `&T` to
+            // serialize the interned value itself, not the box.
+            seq.serialize_element::<T>(&**value)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, T, S, R> Deserialize<'de> for Interner<T, S, R>
+where
+    T: ?Sized + Eq + Hash + ToOwned,
+    T::Owned: Deserialize<'de> + Borrow<T> + Into<Box<T>>,
+    S: BuildHasher + Default,
+    R: RawRwLock + Default,
+{
+    /// Build a fresh interner and re-intern every element of the sequence.
+    ///
+    /// The owned form of `T` (e.g. `String` for `str`, `Vec<u8>` for
+    /// `[u8]`) is what actually gets deserialized and interned.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        type Marker<T, S, R> = PhantomData<fn() -> Interner<T, S, R>>;
+
+        struct InternerVisitor<T: ?Sized, S, R: RawRwLock>(Marker<T, S, R>);
+
+        impl<'de, T, S, R> Visitor<'de> for InternerVisitor<T, S, R>
+        where
+            T: ?Sized + Eq + Hash + ToOwned,
+            T::Owned: Deserialize<'de> + Borrow<T> + Into<Box<T>>,
+            S: BuildHasher + Default,
+            R: RawRwLock + Default,
+        {
+            type Value = Interner<T, S, R>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a sequence of interned values")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let interner = Interner::default();
+                while let Some(value) = seq.next_element::<T::Owned>()? {
+                    interner.intern(value);
+                }
+                Ok(interner)
+            }
+        }
+
+        deserializer.deserialize_seq(InternerVisitor(PhantomData))
+    }
+}
@@ -0,0 +1,243 @@
+//! Implements [`SpinRawRwLock`], a spin-based [`lock_api::RawRwLock`] (and
+//! [`lock_api::RawRwLockUpgrade`]) for `no_std` users who have no other
+//! `RawRwLock` backend available.
+
+use core::{
+    fmt,
+    marker::PhantomData,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use lock_api::{RawRwLock, RawRwLockUpgrade};
+
+/// What a [`SpinRawRwLock`] does between failed attempts to acquire a lock.
+///
+/// Mirrors the relax-strategy pattern used by the `spin` crate: busy-spin
+/// ([`Spin`]) for the shortest critical sections, or (with `std`) yield to
+/// the OS scheduler ([`Yield`]) when contention is expected to last longer
+/// than a few spins.
+pub trait RelaxStrategy {
+    /// Called once per failed attempt to acquire the lock.
+    fn relax();
+}
+
+/// Busy-spins, hinting the CPU via [`core::hint::spin_loop`].
+///
+/// The default [`RelaxStrategy`] for [`SpinRawRwLock`]; works in `no_std`.
+#[derive(Debug, Default)]
+pub struct Spin;
+
+impl RelaxStrategy for Spin {
+    fn relax() {
+        core::hint::spin_loop();
+    }
+}
+
+/// Yields the current thread to the OS scheduler between spins.
+///
+/// Only available with the `std` feature, since yielding requires an OS
+/// thread to yield.
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct Yield;
+
+#[cfg(feature = "std")]
+impl RelaxStrategy for Yield {
+    fn relax() {
+        std::thread::yield_now();
+    }
+}
+
+// Bit layout of `SpinRawRwLock::state`:
+// - bit 0 (WRITER): an exclusive lock is held
+// - bit 1 (UPGRADED): the single upgradable-read lock is held
+// - bits 2.. (READER units): number of active shared-read locks
+const WRITER: usize = 1;
+const UPGRADED: usize = 1 << 1;
+const READER: usize = 1 << 2;
+
+/// A spin-based [`RawRwLock`] usable in `no_std`, with a pluggable
+/// [`RelaxStrategy`] for what to do between spin attempts.
+///
+/// A writer only proceeds once the state is entirely clear (no readers, no
+/// upgradable-read holder, no other writer). An upgradable-read holder
+/// blocks other writers and upgraders, but still lets shared readers join
+/// in, and can later [`upgrade`](RawRwLockUpgrade::upgrade) to an exclusive
+/// lock once the readers have drained.
+pub struct SpinRawRwLock<Relax = Spin> {
+    state: AtomicUsize,
+    _relax: PhantomData<Relax>,
+}
+
+#[allow(unsafe_code)]
+unsafe impl<Relax> Send for SpinRawRwLock<Relax> {}
+#[allow(unsafe_code)]
+unsafe impl<Relax> Sync for SpinRawRwLock<Relax> {}
+
+impl<Relax: RelaxStrategy> Default for SpinRawRwLock<Relax> {
+    fn default() -> Self {
+        <Self as RawRwLock>::INIT
+    }
+}
+
+impl<Relax> fmt::Debug for SpinRawRwLock<Relax> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SpinRawRwLock")
+            .field("state", &self.state.load(Ordering::Relaxed))
+            .finish()
+    }
+}
+
+#[allow(unsafe_code)]
+unsafe impl<Relax: RelaxStrategy> RawRwLock for SpinRawRwLock<Relax> {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const INIT: Self = SpinRawRwLock {
+        state: AtomicUsize::new(0),
+        _relax: PhantomData,
+    };
+
+    type GuardMarker = lock_api::GuardSend;
+
+    fn lock_shared(&self) {
+        while !self.try_lock_shared() {
+            Relax::relax();
+        }
+    }
+
+    fn try_lock_shared(&self) -> bool {
+        let state = self.state.fetch_add(READER, Ordering::Acquire);
+
+        if state & WRITER != 0 {
+            // a writer is active; back out the reader count we just added
+            self.state.fetch_sub(READER, Ordering::Release);
+            false
+        } else {
+            true
+        }
+    }
+
+    unsafe fn unlock_shared(&self) {
+        self.state.fetch_sub(READER, Ordering::Release);
+    }
+
+    fn lock_exclusive(&self) {
+        while !self.try_lock_exclusive() {
+            Relax::relax();
+        }
+    }
+
+    fn try_lock_exclusive(&self) -> bool {
+        self.state
+            .compare_exchange(0, WRITER, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+    }
+
+    unsafe fn unlock_exclusive(&self) {
+        self.state.fetch_and(!WRITER, Ordering::Release);
+    }
+}
+
+#[allow(unsafe_code)]
+unsafe impl<Relax: RelaxStrategy> RawRwLockUpgrade for SpinRawRwLock<Relax> {
+    fn lock_upgradable(&self) {
+        while !self.try_lock_upgradable() {
+            Relax::relax();
+        }
+    }
+
+    fn try_lock_upgradable(&self) -> bool {
+        let state = self.state.fetch_or(UPGRADED, Ordering::Acquire);
+
+        if state & (WRITER | UPGRADED) != 0 {
+            // a writer, or another upgradable-read holder, is active;
+            // only clear the UPGRADED bit if we were the one who set it
+            if state & UPGRADED == 0 {
+                self.state.fetch_and(!UPGRADED, Ordering::Release);
+            }
+            false
+        } else {
+            true
+        }
+    }
+
+    unsafe fn unlock_upgradable(&self) {
+        self.state.fetch_and(!UPGRADED, Ordering::Release);
+    }
+
+    unsafe fn upgrade(&self) {
+        while !self.try_upgrade() {
+            Relax::relax();
+        }
+    }
+
+    unsafe fn try_upgrade(&self) -> bool {
+        // the only readers left can be us: transition directly from
+        // "upgradable lock, no shared readers" to "exclusive lock"
+        self.state
+            .compare_exchange(UPGRADED, WRITER, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shared_locks_are_reentrant_and_exclude_writers() {
+        let lock = SpinRawRwLock::<Spin>::INIT;
+
+        assert!(lock.try_lock_shared());
+        assert!(lock.try_lock_shared());
+        assert!(!lock.try_lock_exclusive());
+
+        unsafe { lock.unlock_shared() };
+        assert!(!lock.try_lock_exclusive());
+
+        unsafe { lock.unlock_shared() };
+        assert!(lock.try_lock_exclusive());
+    }
+
+    #[test]
+    fn exclusive_lock_excludes_readers_and_writers() {
+        let lock = SpinRawRwLock::<Spin>::INIT;
+
+        assert!(lock.try_lock_exclusive());
+        assert!(!lock.try_lock_shared());
+        assert!(!lock.try_lock_exclusive());
+
+        unsafe { lock.unlock_exclusive() };
+        assert!(lock.try_lock_shared());
+    }
+
+    #[test]
+    fn only_one_upgradable_reader_at_a_time() {
+        let lock = SpinRawRwLock::<Spin>::INIT;
+
+        assert!(lock.try_lock_upgradable());
+        assert!(!lock.try_lock_upgradable());
+        // an upgradable-read holder still lets shared readers in
+        assert!(lock.try_lock_shared());
+
+        unsafe { lock.unlock_shared() };
+        unsafe { lock.unlock_upgradable() };
+        assert!(lock.try_lock_upgradable());
+    }
+
+    #[test]
+    fn upgrade_waits_for_outstanding_readers() {
+        let lock = SpinRawRwLock::<Spin>::INIT;
+
+        assert!(lock.try_lock_upgradable());
+        assert!(lock.try_lock_shared());
+
+        // a shared reader is still outstanding, so the upgrade must not
+        // succeed yet
+        assert!(!unsafe { lock.try_upgrade() });
+
+        unsafe { lock.unlock_shared() };
+        assert!(unsafe { lock.try_upgrade() });
+
+        unsafe { lock.unlock_exclusive() };
+    }
+}